@@ -1,4 +1,4 @@
-use crate::utils::FromPayload;
+use crate::utils::{parse_frame_preamble, FromPayload, ParseError, TryFromPayload};
 use byteorder::{ByteOrder, BigEndian};
 
 /// Responses to commands returned by the R502. Names are the same as commands.
@@ -12,6 +12,46 @@ pub enum Reply {
 
     /// Contains result of acquiring an image
     GenImg(GenImgResult),
+
+    /// Contains result of generating a character file from the image buffer
+    GenChar(GenCharResult),
+
+    /// Contains result of merging CharBuffer1 and CharBuffer2 into a template
+    RegModel(RegModelResult),
+
+    /// Contains result of storing a template in the finger library
+    Store(StoreResult),
+
+    /// Contains result of searching the finger library for a matching template
+    Search(SearchResult),
+
+    /// Contains result of matching CharBuffer1 against CharBuffer2
+    Match(MatchResult),
+
+    /// Contains result of deleting templates from the finger library
+    DeleteChar(DeleteCharResult),
+
+    /// Contains result of clearing the finger library
+    Empty(EmptyResult),
+
+    /// Contains the number of valid templates stored in the finger library
+    TemplateNum(TemplateNumResult),
+
+    /// Acknowledges the start of an image upload; the image itself follows as a
+    /// sequence of data packets, see [`crate::transfer::Reassembler`]
+    UpImage(UpImageResult),
+
+    /// Acknowledges the start of an image download; the image itself is sent as a
+    /// sequence of data packets, see [`crate::transfer::Reassembler`]
+    DownImage(DownImageResult),
+
+    /// Acknowledges the start of a template upload; the template itself follows as a
+    /// sequence of data packets, see [`crate::transfer::Reassembler`]
+    UpChar(UpCharResult),
+
+    /// Acknowledges the start of a template download; the template itself is sent as
+    /// a sequence of data packets, see [`crate::transfer::Reassembler`]
+    DownChar(DownCharResult),
 }
 
 #[derive(Debug)]
@@ -22,7 +62,7 @@ pub struct ReadSysParaResult {
     pub checksum: u16,
 }
 
-impl FromPayload
+impl TryFromPayload
 for ReadSysParaResult {
     // Expected packet:
     // headr  | 0xEF 0x01 [2]
@@ -32,13 +72,22 @@ for ReadSysParaResult {
     // confrm | 0x0F [1]
     // params | (params) [16]
     // chksum | checksum [2]
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 28)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: frame.data[0],
+            system_parameters: SystemParameters::try_from_payload(&frame.data[1..17])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+impl FromPayload
+for ReadSysParaResult {
+    /// Deprecated: panics on malformed payloads, use [`TryFromPayload::try_from_payload`] instead.
     fn from_payload(payload: &[u8]) -> Self {
-        return Self {
-            address: BigEndian::read_u32(&payload[2..6]),
-            confirmation_code: payload[9],
-            checksum: BigEndian::read_u16(&payload[26..28]),
-            system_parameters: SystemParameters::from_payload(&payload[10..26]),
-        };
+        Self::try_from_payload(payload).expect("malformed ReadSysPara reply")
     }
 }
 
@@ -50,14 +99,23 @@ pub struct VfyPwdResult {
     pub checksum: u16,
 }
 
+impl TryFromPayload
+for VfyPwdResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: PasswordVerificationState::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
 impl FromPayload
 for VfyPwdResult {
+    /// Deprecated: panics on malformed payloads, use [`TryFromPayload::try_from_payload`] instead.
     fn from_payload(payload: &[u8]) -> Self {
-        return Self {
-            address: BigEndian::read_u32(&payload[2..6]),
-            confirmation_code: PasswordVerificationState::from(payload[9]),
-            checksum: BigEndian::read_u16(&payload[10..12]),
-        };
+        Self::try_from_payload(payload).expect("malformed VfyPwd reply")
     }
 }
 
@@ -69,14 +127,23 @@ pub struct GenImgResult {
     pub checksum: u16,
 }
 
+impl TryFromPayload
+for GenImgResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: GenImgStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
 impl FromPayload
 for GenImgResult {
+    /// Deprecated: panics on malformed payloads, use [`TryFromPayload::try_from_payload`] instead.
     fn from_payload(payload: &[u8]) -> Self {
-        return Self {
-            address: BigEndian::read_u32(&payload[2..6]),
-            confirmation_code: GenImgStatus::from(payload[9]),
-            checksum: BigEndian::read_u16(&payload[10..12]),
-        };
+        Self::try_from_payload(payload).expect("malformed GenImg reply")
     }
 }
 
@@ -94,17 +161,21 @@ pub struct SystemParameters {
     pub finger_library_size: u16,
 
     /// Security level [1-5]
+    ///
+    /// See [`Self::security_level()`] for the typed [`SecurityLevel`].
     pub security_level: u16,
 
     /// Device address, in case you forgot, but then you'd need the device address to send it the
     /// `ReadSysPara` command... 🤔
     pub device_address: u32,
 
-    /// Packet size. Actually a size code [0-3]:\ 
-    /// 0 = 32 bytes\ 
-    /// 1 = 64 bytes\ 
-    /// 2 = 128 bytes (the default)\ 
+    /// Packet size. Actually a size code [0-3]:\
+    /// 0 = 32 bytes\
+    /// 1 = 64 bytes\
+    /// 2 = 128 bytes (the default)\
     /// 3 = 256 bytes
+    ///
+    /// See [`Self::packet_size_bytes`] for the decoded byte count.
     pub packet_size: u16,
 
     /// Baud setting. To get actual baud value, multiply by 9600.
@@ -113,6 +184,8 @@ pub struct SystemParameters {
     /// the device, and consequently what's the maximum here. In one place, it says the range is
     /// [1-6], in another it states the max baud rate is 115,200 giving [1-12].
     /// The default value is 6 for 57,600‬ baud.
+    ///
+    /// See [`Self::baud_rate`] for the decoded bps value.
     pub baud_setting: u16,
 }
 
@@ -120,7 +193,7 @@ impl SystemParameters {
     /// True if the R502 is busy executing another command.
     ///
     /// *Busy* in the datasheet.
-    pub fn busy(self) -> bool {
+    pub fn busy(&self) -> bool {
         return self.status_register & (1u16 << 0) != 0;
     }
 
@@ -128,43 +201,102 @@ impl SystemParameters {
     /// always check the response to the actual matching request.
     ///
     /// *Pass* in the datasheet.
-    pub fn has_finger_match(self) -> bool {
+    pub fn has_finger_match(&self) -> bool {
         return self.status_register & (1u16 << 1) != 0;
     }
 
     /// True if the password given in the handshake is correct.
     ///
     /// *PWD* in the datasheet.
-    pub fn password_ok(self) -> bool {
+    pub fn password_ok(&self) -> bool {
         return self.status_register & (1u16 << 2) != 0;
     }
 
     /// True if the image buffer contains a valid image.
     ///
     /// *ImgBufStat* in the datasheet.
-    pub fn has_valid_image(self) -> bool {
+    pub fn has_valid_image(&self) -> bool {
         return self.status_register & (1u16 << 3) != 0;
     }
+
+    /// The negotiated packet size, decoded from [`Self::packet_size`]'s size code
+    /// into an actual byte count.
+    pub fn packet_size_bytes(&self) -> usize {
+        match self.packet_size {
+            0 => 32,
+            1 => 64,
+            2 => 128,
+            _ => 256,
+        }
+    }
+
+    /// The negotiated baud rate, decoded from [`Self::baud_setting`] into an actual
+    /// bits-per-second value.
+    pub fn baud_rate(&self) -> u32 {
+        self.baud_setting as u32 * 9600
+    }
+
+    /// The configured [`SecurityLevel`], decoded from [`Self::security_level`].
+    pub fn security_level(&self) -> Result<SecurityLevel, ParseError> {
+        SecurityLevel::try_from(self.security_level)
+    }
 }
 
-impl FromPayload
+impl TryFromPayload
 for SystemParameters {
-    fn from_payload(payload: &[u8]) -> SystemParameters {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
         // HZ R502's datasheet is a little inconsistent - sometimes the sizes are given in bytes
         // and sometimes in words; words are 16 bit (2 byte).
         // Pick a flipping unit and stick with it!
-        SystemParameters {
+        const EXPECTED_LEN: usize = 16;
+        if payload.len() < EXPECTED_LEN {
+            return Err(ParseError::TooShort { expected: EXPECTED_LEN, got: payload.len() });
+        }
+
+        Ok(SystemParameters {
             status_register: BigEndian::read_u16(&payload[0..2]),
             system_identifier_code: BigEndian::read_u16(&payload[2..4]),
             finger_library_size: BigEndian::read_u16(&payload[4..6]),
             security_level: BigEndian::read_u16(&payload[6..8]),
             device_address: BigEndian::read_u32(&payload[8..12]),
             packet_size: BigEndian::read_u16(&payload[12..14]),
-            baud_setting: BigEndian::read_u16(&payload[12..16]),
+            baud_setting: BigEndian::read_u16(&payload[14..16]),
+        })
+    }
+}
+
+/// Security level, traded off against the false acceptance/rejection rate - higher
+/// is stricter. See [`SystemParameters::security_level`].
+#[derive(Debug)]
+pub enum SecurityLevel {
+    Lowest,
+    Low,
+    Medium,
+    High,
+    Highest,
+}
+
+impl SecurityLevel {
+    pub fn try_from(level: u16) -> Result<Self, ParseError> {
+        match level {
+            1 => Ok(Self::Lowest),
+            2 => Ok(Self::Low),
+            3 => Ok(Self::Medium),
+            4 => Ok(Self::High),
+            5 => Ok(Self::Highest),
+            _ => Err(ParseError::InvalidSecurityLevel { level }),
         }
     }
 }
 
+impl FromPayload
+for SystemParameters {
+    /// Deprecated: panics on malformed payloads, use [`TryFromPayload::try_from_payload`] instead.
+    fn from_payload(payload: &[u8]) -> SystemParameters {
+        Self::try_from_payload(payload).expect("malformed SystemParameters")
+    }
+}
+
 /// Enum for the password handshake result
 #[derive(Debug)]
 pub enum PasswordVerificationState {
@@ -174,13 +306,18 @@ pub enum PasswordVerificationState {
 }
 
 impl PasswordVerificationState {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Correct),
+            0x13 => Ok(Self::Incorrect),
+            0x01 => Ok(Self::Error),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+
+    #[deprecated(note = "panics on unknown confirmation codes; use try_from instead")]
     pub fn from(byte: u8) -> Self {
-        return match byte {
-            0x00 => Self::Correct,
-            0x13 => Self::Incorrect,
-            0x01 => Self::Error,
-            _ => panic!("Invalid VfyPwdResult: {:02x}", byte),
-        };
+        Self::try_from(byte).unwrap_or_else(|_| panic!("Invalid VfyPwdResult: {:02x}", byte))
     }
 }
 
@@ -200,13 +337,523 @@ pub enum GenImgStatus {
 }
 
 impl GenImgStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x02 => Ok(Self::FingerNotDetected),
+            0x03 => Ok(Self::ImageNotCaptured),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+
+    #[deprecated(note = "panics on unknown confirmation codes; use try_from instead")]
     pub fn from(byte: u8) -> Self {
-        return match byte {
-            0x00 => Self::Success,
-            0x01 => Self::PacketError,
-            0x02 => Self::FingerNotDetected,
-            0x03 => Self::ImageNotCaptured,
-            _ => panic!("Invalid GenImgStatus: {:02x}", byte),
-        };
+        Self::try_from(byte).unwrap_or_else(|_| panic!("Invalid GenImgStatus: {:02x}", byte))
+    }
+}
+
+#[derive(Debug)]
+pub struct GenCharResult {
+    pub address: u32,
+    /// Character file generation result
+    pub confirmation_code: GenCharStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for GenCharResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: GenCharStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum GenCharStatus {
+    /// Character file generated successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// Image is too disorderly to generate a character file
+    DisorderlyImage,
+
+    /// Image has too few feature points, or is too small
+    TooFewFeaturePoints,
+
+    /// Image is invalid
+    InvalidImage,
+}
+
+impl GenCharStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x06 => Ok(Self::DisorderlyImage),
+            0x07 => Ok(Self::TooFewFeaturePoints),
+            0x15 => Ok(Self::InvalidImage),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RegModelResult {
+    pub address: u32,
+    /// Template merge result
+    pub confirmation_code: RegModelStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for RegModelResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: RegModelStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum RegModelStatus {
+    /// CharBuffer1 and CharBuffer2 combined into a template successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// CharBuffer1 and CharBuffer2 are not from the same finger
+    FingersMismatch,
+}
+
+impl RegModelStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x0A => Ok(Self::FingersMismatch),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StoreResult {
+    pub address: u32,
+    /// Template storage result
+    pub confirmation_code: StoreStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for StoreResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: StoreStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum StoreStatus {
+    /// Template stored successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// Target page ID is beyond the finger library's capacity
+    PageIdOutOfRange,
+
+    /// Error writing the template to flash
+    FlashWriteError,
+}
+
+impl StoreStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x0B => Ok(Self::PageIdOutOfRange),
+            0x18 => Ok(Self::FlashWriteError),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchResult {
+    pub address: u32,
+    /// Search result
+    pub confirmation_code: SearchStatus,
+
+    /// Page ID of the matched template in the finger library
+    pub page_id: u16,
+
+    /// Match score between the image buffer and the matched template
+    pub match_score: u16,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for SearchResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 16)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: SearchStatus::try_from(frame.data[0])?,
+            page_id: BigEndian::read_u16(&frame.data[1..3]),
+            match_score: BigEndian::read_u16(&frame.data[3..5]),
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum SearchStatus {
+    /// A matching template was found
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// No matching template was found in the finger library
+    NoMatchFound,
+}
+
+impl SearchStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x09 => Ok(Self::NoMatchFound),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MatchResult {
+    pub address: u32,
+    /// Match result
+    pub confirmation_code: MatchStatus,
+
+    /// Match score between CharBuffer1 and CharBuffer2
+    pub match_score: u16,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for MatchResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 14)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: MatchStatus::try_from(frame.data[0])?,
+            match_score: BigEndian::read_u16(&frame.data[1..3]),
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum MatchStatus {
+    /// CharBuffer1 and CharBuffer2 match
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// CharBuffer1 and CharBuffer2 do not match
+    NoMatch,
+}
+
+impl MatchStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x08 => Ok(Self::NoMatch),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteCharResult {
+    pub address: u32,
+    /// Template deletion result
+    pub confirmation_code: DeleteCharStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for DeleteCharResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: DeleteCharStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum DeleteCharStatus {
+    /// Template(s) deleted successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// Failed to delete the template(s)
+    DeleteFailed,
+}
+
+impl DeleteCharStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x10 => Ok(Self::DeleteFailed),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EmptyResult {
+    pub address: u32,
+    /// Finger library clear result
+    pub confirmation_code: EmptyStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for EmptyResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: EmptyStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum EmptyStatus {
+    /// Finger library cleared successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// Failed to clear the finger library
+    ClearFailed,
+}
+
+impl EmptyStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x11 => Ok(Self::ClearFailed),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TemplateNumResult {
+    pub address: u32,
+    /// Template count query result
+    pub confirmation_code: TemplateNumStatus,
+
+    /// Number of valid templates stored in the finger library
+    pub template_num: u16,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for TemplateNumResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 14)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: TemplateNumStatus::try_from(frame.data[0])?,
+            template_num: BigEndian::read_u16(&frame.data[1..3]),
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum TemplateNumStatus {
+    /// Template count read successfully
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+}
+
+impl TemplateNumStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+/// Acknowledgement shared by the four data-carrying commands (`UpImage`, `DownImage`,
+/// `UpChar`, `DownChar`): it precedes the actual data packets, see
+/// [`crate::transfer::Reassembler`].
+#[derive(Debug)]
+pub enum TransferAckStatus {
+    /// The device is ready to stream/receive data packets
+    Success,
+
+    /// Error reading packet from the host
+    PacketError,
+
+    /// Error uploading the template (`UpChar`)
+    UploadTemplateError,
+
+    /// Failed to receive the following data packets
+    CannotReceiveFollowingPackets,
+
+    /// Error uploading the image (`UpImage`)
+    UploadImageError,
+}
+
+impl TransferAckStatus {
+    pub fn try_from(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            0x00 => Ok(Self::Success),
+            0x01 => Ok(Self::PacketError),
+            0x0D => Ok(Self::UploadTemplateError),
+            0x0E => Ok(Self::CannotReceiveFollowingPackets),
+            0x0F => Ok(Self::UploadImageError),
+            _ => Err(ParseError::UnknownConfirmationCode { code: byte }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UpImageResult {
+    pub address: u32,
+    /// Image upload acknowledgement
+    pub confirmation_code: TransferAckStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for UpImageResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: TransferAckStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DownImageResult {
+    pub address: u32,
+    /// Image download acknowledgement
+    pub confirmation_code: TransferAckStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for DownImageResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: TransferAckStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct UpCharResult {
+    pub address: u32,
+    /// Template upload acknowledgement
+    pub confirmation_code: TransferAckStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for UpCharResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: TransferAckStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DownCharResult {
+    pub address: u32,
+    /// Template download acknowledgement
+    pub confirmation_code: TransferAckStatus,
+    pub checksum: u16,
+}
+
+impl TryFromPayload
+for DownCharResult {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError> {
+        let frame = parse_frame_preamble(payload, 12)?;
+        Ok(Self {
+            address: frame.address,
+            confirmation_code: TransferAckStatus::try_from(frame.data[0])?,
+            checksum: frame.checksum,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_parameters_reads_baud_setting_from_its_own_bytes() {
+        // status_register, system_identifier_code, finger_library_size,
+        // security_level, device_address, packet_size, baud_setting
+        let payload: [u8; 16] = [
+            0x00, 0x01, 0x00, 0x09, 0x00, 0xC8, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x02,
+            0x00, 0x06,
+        ];
+
+        let params = SystemParameters::try_from_payload(&payload).unwrap();
+
+        // packet_size (0x0002) and baud_setting (0x0006) must not alias - a prior bug
+        // read baud_setting from the same two bytes as packet_size.
+        assert_eq!(params.packet_size, 2);
+        assert_eq!(params.baud_setting, 6);
+        assert_eq!(params.baud_rate(), 6 * 9600);
     }
 }
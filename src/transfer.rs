@@ -0,0 +1,117 @@
+use crate::utils::{frame_checksum, ParseError};
+use byteorder::{BigEndian, ByteOrder};
+use std::ops::RangeInclusive;
+
+/// Identifier byte for a packet carrying a chunk of an `UpImage`/`UpChar` stream.
+const DATA_PACKET_IDENT: u8 = 0x02;
+
+/// Identifier byte for the final packet of an `UpImage`/`UpChar` stream.
+const END_PACKET_IDENT: u8 = 0x08;
+
+/// Reassembles the sequence of data packets (identifier [`DATA_PACKET_IDENT`]),
+/// terminated by an end packet (identifier [`END_PACKET_IDENT`]), that the R502
+/// streams after an `UpImage`/`UpChar` acknowledgement into a single contiguous
+/// buffer.
+///
+/// The chunk length is driven by `packet_size`, read out of `SystemParameters` via
+/// [`crate::responses::SystemParameters::packet_size_bytes`], rather than a
+/// hardcoded constant - a device configured for 256-byte packets would otherwise
+/// have its stream silently truncated or misaligned.
+pub struct Reassembler {
+    packet_size: usize,
+    accepted_len: RangeInclusive<usize>,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl Reassembler {
+    /// Creates a reassembler that accepts `accepted_len` total bytes (inclusive) so
+    /// that a stuck or lying device streaming packets forever can't make it
+    /// allocate without limit.
+    pub fn new(packet_size: usize, accepted_len: RangeInclusive<usize>) -> Self {
+        Self {
+            packet_size,
+            accepted_len,
+            buffer: Vec::with_capacity(packet_size),
+            done: false,
+        }
+    }
+
+    /// True once an end packet has been consumed; [`Self::finish`] can be called.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Appends one already-validated packet's data body to the buffer; `identifier`
+    /// marks whether it was the end packet.
+    fn push_frame(&mut self, identifier: u8, data: &[u8]) -> Result<(), ParseError> {
+        if self.buffer.len() + data.len() > *self.accepted_len.end() {
+            return Err(ParseError::SizeOutOfRange {
+                accepted: self.accepted_len.clone(),
+                got: self.buffer.len() + data.len(),
+            });
+        }
+
+        self.buffer.extend_from_slice(data);
+        self.done = identifier == END_PACKET_IDENT;
+        Ok(())
+    }
+
+    /// Parses and consumes one data or end packet.
+    pub fn push_packet(&mut self, payload: &[u8]) -> Result<(), ParseError> {
+        // headr  | 0xEF 0x01 [2]
+        // addr   | cmd.address [4]
+        // ident  | 0x02 or 0x08 [1]
+        // length | data.len() + 2 [2]
+        // data   | (data) [packet_size, or less for the final packet]
+        // chksum | checksum [2]
+        const HEADER_LEN: usize = 9;
+        if payload.len() < HEADER_LEN + 2 {
+            return Err(ParseError::TooShort { expected: HEADER_LEN + 2, got: payload.len() });
+        }
+        if payload[0..2] != [0xEF, 0x01] {
+            return Err(ParseError::BadHeader);
+        }
+
+        let identifier = payload[6];
+        if identifier != DATA_PACKET_IDENT && identifier != END_PACKET_IDENT {
+            return Err(ParseError::BadHeader);
+        }
+
+        let length = BigEndian::read_u16(&payload[7..9]) as usize;
+        let data_len = length.saturating_sub(2);
+        if payload.len() < HEADER_LEN + data_len + 2 {
+            return Err(ParseError::TooShort {
+                expected: HEADER_LEN + data_len + 2,
+                got: payload.len(),
+            });
+        }
+
+        // Only the end packet is allowed to be shorter than the negotiated packet size.
+        if identifier == DATA_PACKET_IDENT && data_len != self.packet_size {
+            return Err(ParseError::UnexpectedChunkLength { expected: self.packet_size, got: data_len });
+        }
+
+        let data = &payload[HEADER_LEN..HEADER_LEN + data_len];
+        let checksum = BigEndian::read_u16(&payload[HEADER_LEN + data_len..HEADER_LEN + data_len + 2]);
+        let computed = frame_checksum(identifier, &payload[7..9], data);
+        if checksum != computed {
+            return Err(ParseError::ChecksumMismatch { expected: checksum, computed });
+        }
+
+        self.push_frame(identifier, data)
+    }
+
+    /// Consumes the reassembler, returning the concatenated payload once an end
+    /// packet has been seen and the total size falls within the accepted range.
+    pub fn finish(self) -> Result<Vec<u8>, ParseError> {
+        if !self.done || !self.accepted_len.contains(&self.buffer.len()) {
+            return Err(ParseError::SizeOutOfRange {
+                accepted: self.accepted_len,
+                got: self.buffer.len(),
+            });
+        }
+
+        Ok(self.buffer)
+    }
+}
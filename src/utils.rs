@@ -0,0 +1,106 @@
+/// Parses a raw UART payload into a strongly-typed structure.
+///
+/// Implementors historically panicked on malformed input - prefer [`TryFromPayload`]
+/// for anything reading off the wire.
+pub trait FromPayload {
+    fn from_payload(payload: &[u8]) -> Self;
+}
+
+/// Parses a raw UART payload into a strongly-typed structure, reporting malformed
+/// frames instead of panicking.
+///
+/// A noisy or half-connected serial line will eventually hand us a truncated frame,
+/// an unrecognised confirmation code, or a flipped bit - none of that should take
+/// the whole process down.
+pub trait TryFromPayload: Sized {
+    fn try_from_payload(payload: &[u8]) -> Result<Self, ParseError>;
+}
+
+/// Reasons a reply payload could not be parsed.
+#[derive(Debug)]
+pub enum ParseError {
+    /// Payload was shorter than the fixed layout requires.
+    TooShort { expected: usize, got: usize },
+
+    /// Payload did not start with the expected 0xEF 0x01 header, or a data-transfer
+    /// packet carried an identifier other than the data (0x02) or end (0x08) one.
+    BadHeader,
+
+    /// Confirmation code byte did not match any known status for this reply.
+    UnknownConfirmationCode { code: u8 },
+
+    /// Computed checksum did not match the checksum carried in the frame.
+    ChecksumMismatch { expected: u16, computed: u16 },
+
+    /// Reassembled data fell outside the accepted size range, or was taken before its
+    /// end packet arrived.
+    SizeOutOfRange { accepted: std::ops::RangeInclusive<usize>, got: usize },
+
+    /// `security_level` was outside the datasheet's documented [1-5] range.
+    InvalidSecurityLevel { level: u16 },
+
+    /// A data packet's chunk length didn't match the negotiated packet size.
+    UnexpectedChunkLength { expected: usize, got: usize },
+}
+
+/// Computes the R502 frame checksum: the sum of the identifier byte, the two length
+/// bytes, and every byte of the frame's payload, truncated to 16 bits.
+///
+/// This mirrors how authenticated-packet readers reject frames whose trailing
+/// MAC/checksum doesn't match the body before trusting the contents.
+pub fn frame_checksum(identifier: u8, length: &[u8], payload: &[u8]) -> u16 {
+    let mut sum: u32 = identifier as u32;
+    for &byte in length {
+        sum += byte as u32;
+    }
+    for &byte in payload {
+        sum += byte as u32;
+    }
+
+    (sum & 0xFFFF) as u16
+}
+
+/// The checksum-verified body of a fixed-layout reply, with the header, address,
+/// identifier, length, and checksum already accounted for.
+pub struct FramePreamble<'a> {
+    pub address: u32,
+    /// Confirmation code onward, up to but excluding the trailing checksum.
+    pub data: &'a [u8],
+    pub checksum: u16,
+}
+
+/// Parses and checksum-verifies the preamble shared by every fixed-layout reply:
+///
+/// ```text
+/// headr  | 0xEF 0x01 [2]
+/// addr   | cmd.address [4]
+/// ident  | [1]
+/// length | data.len() + 2 [2]
+/// data   | (confirmation code onward) [data.len()]
+/// chksum | checksum [2]
+/// ```
+///
+/// `expected_len` is the total frame length, header through checksum.
+pub fn parse_frame_preamble(payload: &[u8], expected_len: usize) -> Result<FramePreamble<'_>, ParseError> {
+    use byteorder::{BigEndian, ByteOrder};
+
+    if payload.len() < expected_len {
+        return Err(ParseError::TooShort { expected: expected_len, got: payload.len() });
+    }
+    if payload[0..2] != [0xEF, 0x01] {
+        return Err(ParseError::BadHeader);
+    }
+
+    let data = &payload[9..expected_len - 2];
+    let checksum = BigEndian::read_u16(&payload[expected_len - 2..expected_len]);
+    let computed = frame_checksum(payload[6], &payload[7..9], data);
+    if checksum != computed {
+        return Err(ParseError::ChecksumMismatch { expected: checksum, computed });
+    }
+
+    Ok(FramePreamble {
+        address: BigEndian::read_u32(&payload[2..6]),
+        data,
+        checksum,
+    })
+}